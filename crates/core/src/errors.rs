@@ -7,6 +7,320 @@ use crate::kernel::transaction::{CommitBuilderError, TransactionError};
 /// A result returned by delta-rs
 pub type DeltaResult<T, E = DeltaTableError> = Result<T, E>;
 
+/// Detail about a single log-action field that failed to parse while reading a commit
+/// or checkpoint, e.g. `add.stats.minValues.colX` in version `42`.
+///
+/// This is more actionable than a raw `"invalid JSON at line N"` message, since it
+/// names the action type, the offending field, and where in the log it occurred.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid `{action}.{field}` at version {version} (offset {offset}): {source}")]
+pub struct ActionParseError {
+    /// The kind of log action being parsed, e.g. `"add"`, `"remove"`, `"metaData"`, `"protocol"`.
+    pub action: &'static str,
+    /// Dotted path of the field that failed to parse, e.g. `"stats.minValues.colX"`.
+    pub field: String,
+    /// The commit version the offending log line belongs to.
+    pub version: i64,
+    /// Byte offset of the offending line within the log file.
+    pub offset: usize,
+    /// The underlying JSON parse error.
+    #[source]
+    pub source: serde_json::Error,
+}
+
+/// The class of optimistic-concurrency conflict detected when a commit collides with a
+/// concurrently-committed ("winning") version, per Delta's standard conflict-resolution
+/// rules. See [`check_commit_conflict`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConflictType {
+    /// The winning commit only added files to partitions our read predicate did not
+    /// touch. Safe to auto-retry by rebasing the transaction onto the new version.
+    ConcurrentAppend,
+    /// A file our transaction read was removed by the winning commit. The transaction
+    /// must abort, since it may have been computed over data that no longer exists.
+    ConcurrentDeleteRead,
+    /// Both the winning commit and our transaction removed the same file. The
+    /// transaction must abort.
+    ConcurrentDeleteDelete,
+    /// The winning commit changed the table's schema or partitioning. The transaction
+    /// must abort.
+    MetadataChanged,
+    /// The winning commit changed the table's supported reader/writer protocol. The
+    /// transaction must abort.
+    ProtocolChanged,
+}
+
+impl ConflictType {
+    /// Returns `true` if a transaction can safely be rebased onto the winning commit
+    /// and retried, rather than aborted outright.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::ConcurrentAppend)
+    }
+}
+
+/// The set of added/removed file paths and partition values touched by a commit,
+/// together with whether it changed table metadata or protocol. Used by
+/// [`check_commit_conflict`] to classify a conflict against a concurrent transaction.
+#[derive(Debug, Default, Clone)]
+pub struct CommitFileSummary {
+    /// Paths of files added by this commit.
+    pub added_files: std::collections::HashSet<String>,
+    /// Paths of files removed by this commit.
+    pub removed_files: std::collections::HashSet<String>,
+    /// Partition values touched by the files this commit added or removed.
+    pub touched_partitions: std::collections::HashSet<String>,
+    /// Whether this commit changed the table's schema or partitioning.
+    pub metadata_changed: bool,
+    /// Whether this commit changed the table's reader/writer protocol.
+    pub protocol_changed: bool,
+}
+
+/// Run the standard Delta conflict-resolution check between a winning concurrent
+/// commit and our own in-flight transaction.
+///
+/// `read_files` is the exact set of file paths the transaction's read predicate
+/// actually read; when the transaction knows this (the common case: a scan enumerates
+/// the files it reads before writing), it is used directly to detect
+/// [`ConflictType::ConcurrentDeleteRead`] -- a file we read being removed always
+/// conflicts, regardless of which partition it lives in. `read_partitions` is the set
+/// of partition values the read predicate touched; the `ConcurrentDeleteRead` check
+/// uses it only as a coarser fallback when `read_files` is empty (e.g. a metadata-only
+/// read that never enumerated files), and an empty `read_partitions` in that case
+/// means the transaction read the whole table.
+/// The [`ConflictType::ConcurrentAppend`] check below uses `read_partitions` whenever
+/// it is non-empty, and derives the partitions we're known to have touched from
+/// `read_files` when `read_partitions` is empty, so a caller that only populates
+/// `read_files` still gets accurate partition-overlap detection instead of a free pass
+/// from a trivially-empty `read_partitions`. `removed_files` is the set of file paths
+/// our own transaction removes. Returns `Some(ConflictType::ConcurrentAppend)` when the
+/// winner added files only to partitions disjoint from those we read. Returns `None`
+/// when no conflict class applies: the winner changed neither metadata nor protocol,
+/// removed none of the files we read or removed, and either added no files or added
+/// files to partitions we read (including when we read the whole table).
+pub fn check_commit_conflict(
+    winner: &CommitFileSummary,
+    read_partitions: &std::collections::HashSet<String>,
+    read_files: &std::collections::HashSet<String>,
+    removed_files: &std::collections::HashSet<String>,
+) -> Option<ConflictType> {
+    if winner.protocol_changed {
+        return Some(ConflictType::ProtocolChanged);
+    }
+    if winner.metadata_changed {
+        return Some(ConflictType::MetadataChanged);
+    }
+    if !winner.removed_files.is_disjoint(removed_files) {
+        return Some(ConflictType::ConcurrentDeleteDelete);
+    }
+
+    let read_everything = read_partitions.is_empty() && read_files.is_empty();
+
+    if !read_files.is_empty() {
+        if !winner.removed_files.is_disjoint(read_files) {
+            return Some(ConflictType::ConcurrentDeleteRead);
+        }
+    } else if !winner.removed_files.is_empty()
+        && (read_everything || !winner.touched_partitions.is_disjoint(read_partitions))
+    {
+        return Some(ConflictType::ConcurrentDeleteRead);
+    }
+
+    if read_everything {
+        // We read the whole table, so we can't claim the winner's new files landed
+        // outside what we read -- don't report a falsely-safe disjoint append.
+        return None;
+    }
+
+    let touched_by_read: std::collections::HashSet<String> = if !read_partitions.is_empty() {
+        read_partitions.clone()
+    } else {
+        read_files.iter().map(|f| partition_of(f)).collect()
+    };
+
+    if !winner.added_files.is_empty() && winner.touched_partitions.is_disjoint(&touched_by_read) {
+        return Some(ConflictType::ConcurrentAppend);
+    }
+
+    None
+}
+
+/// The partition directory portion of a file path, e.g. `"part=1"` for
+/// `"part=1/a.parquet"`, or the empty string for an unpartitioned file at the table
+/// root. Used to derive touched partitions from `read_files` when `read_partitions`
+/// wasn't supplied.
+fn partition_of(path: &str) -> String {
+    path.rsplit_once('/')
+        .map(|(dir, _)| dir.to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod conflict_tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn set(items: &[&str]) -> HashSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn flags_concurrent_delete_read_by_exact_file_even_across_partitions() {
+        // The winner removed a file in a partition our predicate never scanned, but we
+        // still read that exact file (e.g. it was returned by an earlier snapshot) --
+        // the file-level check must catch this even though the partition-level one
+        // would have missed it.
+        let winner = CommitFileSummary {
+            removed_files: set(&["part=1/a.parquet"]),
+            touched_partitions: set(&["part=1"]),
+            ..Default::default()
+        };
+        let conflict = check_commit_conflict(
+            &winner,
+            &set(&["part=2"]),
+            &set(&["part=1/a.parquet"]),
+            &HashSet::new(),
+        );
+        assert_eq!(conflict, Some(ConflictType::ConcurrentDeleteRead));
+    }
+
+    #[test]
+    fn does_not_over_report_when_read_files_are_disjoint_from_removals() {
+        // A surviving file in the same partition as a removed, unrelated file must not
+        // be treated as a conflict -- this is exactly the over-reporting the
+        // partition-only check used to cause.
+        let winner = CommitFileSummary {
+            removed_files: set(&["part=1/b.parquet"]),
+            touched_partitions: set(&["part=1"]),
+            ..Default::default()
+        };
+        let conflict = check_commit_conflict(
+            &winner,
+            &set(&["part=1"]),
+            &set(&["part=1/a.parquet"]),
+            &HashSet::new(),
+        );
+        assert_eq!(conflict, None);
+    }
+
+    #[test]
+    fn flags_concurrent_delete_delete() {
+        let winner = CommitFileSummary {
+            removed_files: set(&["a.parquet"]),
+            ..Default::default()
+        };
+        let conflict = check_commit_conflict(
+            &winner,
+            &HashSet::new(),
+            &HashSet::new(),
+            &set(&["a.parquet"]),
+        );
+        assert_eq!(conflict, Some(ConflictType::ConcurrentDeleteDelete));
+    }
+
+    #[test]
+    fn flags_metadata_and_protocol_changes_unconditionally() {
+        let metadata = CommitFileSummary {
+            metadata_changed: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            check_commit_conflict(&metadata, &HashSet::new(), &HashSet::new(), &HashSet::new()),
+            Some(ConflictType::MetadataChanged)
+        );
+
+        let protocol = CommitFileSummary {
+            protocol_changed: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            check_commit_conflict(&protocol, &HashSet::new(), &HashSet::new(), &HashSet::new()),
+            Some(ConflictType::ProtocolChanged)
+        );
+    }
+
+    #[test]
+    fn allows_safe_rebase_on_disjoint_concurrent_append() {
+        let winner = CommitFileSummary {
+            added_files: set(&["part=2/c.parquet"]),
+            touched_partitions: set(&["part=2"]),
+            ..Default::default()
+        };
+        let conflict =
+            check_commit_conflict(&winner, &set(&["part=1"]), &HashSet::new(), &HashSet::new());
+        assert_eq!(conflict, Some(ConflictType::ConcurrentAppend));
+        assert!(conflict.unwrap().is_retryable());
+    }
+
+    #[test]
+    fn derives_touched_partitions_from_read_files_for_append_check() {
+        // Only `read_files` is populated (per the docs' own guidance), `read_partitions`
+        // is left empty. The winner appends into the same partition we read via
+        // `read_files` -- this must not be waved through as a safe disjoint append just
+        // because `read_partitions` happens to be empty.
+        let winner = CommitFileSummary {
+            added_files: set(&["part=1/b.parquet"]),
+            touched_partitions: set(&["part=1"]),
+            ..Default::default()
+        };
+        let conflict = check_commit_conflict(
+            &winner,
+            &HashSet::new(),
+            &set(&["part=1/a.parquet"]),
+            &HashSet::new(),
+        );
+        assert_eq!(conflict, None);
+    }
+
+    #[test]
+    fn still_allows_rebase_when_read_files_partition_is_disjoint_from_append() {
+        let winner = CommitFileSummary {
+            added_files: set(&["part=2/b.parquet"]),
+            touched_partitions: set(&["part=2"]),
+            ..Default::default()
+        };
+        let conflict = check_commit_conflict(
+            &winner,
+            &HashSet::new(),
+            &set(&["part=1/a.parquet"]),
+            &HashSet::new(),
+        );
+        assert_eq!(conflict, Some(ConflictType::ConcurrentAppend));
+    }
+
+    #[test]
+    fn reading_the_whole_table_never_reports_a_safe_append() {
+        let winner = CommitFileSummary {
+            added_files: set(&["part=1/a.parquet"]),
+            touched_partitions: set(&["part=1"]),
+            ..Default::default()
+        };
+        let conflict =
+            check_commit_conflict(&winner, &HashSet::new(), &HashSet::new(), &HashSet::new());
+        assert_eq!(conflict, None);
+    }
+}
+
+impl ActionParseError {
+    /// Create an [`ActionParseError`] describing a failure to parse `field` of `action`
+    /// at the given commit `version` and byte `offset` within the log.
+    pub fn new(
+        action: &'static str,
+        field: impl Into<String>,
+        version: i64,
+        offset: usize,
+        source: serde_json::Error,
+    ) -> Self {
+        Self {
+            action,
+            field: field.into(),
+            version,
+            offset,
+            source,
+        }
+    }
+}
+
 /// Delta Table specific error
 #[allow(missing_docs)]
 #[derive(thiserror::Error, Debug)]
@@ -65,6 +379,11 @@ pub enum DeltaTableError {
         line: String,
     },
 
+    /// Error returned when a single field within a log action (`add`, `remove`,
+    /// `metaData`, `protocol`, ...) failed to parse, e.g. `add.stats.minValues.colX`.
+    #[error(transparent)]
+    ActionParse(#[from] ActionParseError),
+
     /// Error returned when the DeltaTable has an invalid version.
     #[error("Invalid table version: {0}")]
     InvalidVersion(i64),
@@ -164,6 +483,16 @@ pub enum DeltaTableError {
     #[error("Delta transaction failed, version {0} already exists.")]
     VersionAlreadyExists(i64),
 
+    /// Error returned when a commit collides with a concurrently-committed version and
+    /// the standard Delta conflict-resolution check classifies the collision.
+    #[error("Delta transaction failed, conflicts with concurrent commit {version} ({conflict:?})")]
+    Conflict {
+        /// The class of conflict detected between the two commits.
+        conflict: ConflictType,
+        /// The version of the commit that won the race.
+        version: i64,
+    },
+
     /// Error returned when user attempts to commit actions that don't belong to the next version.
     #[error("Delta transaction failed, version {0} does not follow {1}")]
     VersionMismatch(i64, i64),
@@ -265,4 +594,268 @@ impl DeltaTableError {
     pub fn generic(msg: impl ToString) -> Self {
         Self::Generic(msg.to_string())
     }
+
+    /// Classify this error so that commit loops and object-store callers can decide
+    /// whether to retry with backoff, rebase and retry, or give up immediately.
+    ///
+    /// See [`ErrorKind`] for the meaning of each category.
+    pub fn classify(&self) -> ErrorKind {
+        match self {
+            Self::VersionAlreadyExists(_) | Self::VersionMismatch(_, _) | Self::Conflict { .. } => {
+                ErrorKind::Conflict
+            }
+
+            Self::SchemaMismatch { .. }
+            | Self::InvalidData { .. }
+            | Self::PartitionError { .. }
+            | Self::InvalidPartitionFilter { .. }
+            | Self::ColumnsNotPartitioned { .. }
+            | Self::InvalidVersion(_)
+            | Self::InvalidTableLocation(_)
+            | Self::MissingFeature { .. }
+            | Self::ChangeDataNotRecorded { .. }
+            | Self::ChangeDataNotEnabled { .. }
+            | Self::ChangeDataInvalidVersionRange { .. }
+            | Self::ChangeDataTimestampGreaterThanCommit { .. }
+            | Self::NoStartingVersionOrTimestamp => ErrorKind::InvalidInput,
+
+            Self::ObjectStore { source } => classify_object_store_error(source),
+            Self::Io { .. } => ErrorKind::Transient,
+            Self::ActionParse(_) => ErrorKind::Fatal,
+
+            _ => ErrorKind::Fatal,
+        }
+    }
+
+    /// Returns `true` if retrying the operation that produced this error, after an
+    /// appropriate backoff, stands a reasonable chance of succeeding.
+    pub fn is_transient(&self) -> bool {
+        matches!(self.classify(), ErrorKind::Transient)
+    }
+
+    /// A stable, versioned identifier for this error variant, intended for language
+    /// bindings (Python, etc.) that need to branch on the failure kind without
+    /// depending on the wording of [`Display`](std::fmt::Display).
+    ///
+    /// Codes are documented here and only ever added to, never renamed or removed, so
+    /// bindings can match on them across delta-rs releases.
+    ///
+    /// Two pairs of variants intentionally share a code because they represent the
+    /// same condition from the binding's point of view and only differ in how
+    /// delta-rs itself got there:
+    /// - `KernelError`/`Kernel` both mean "the kernel crate rejected this", one via a
+    ///   direct `#[from] delta_kernel::error::Error` conversion and the other via our
+    ///   own [`kernel::Error`](crate::kernel::Error) wrapper -- both share
+    ///   `"DELTA_KERNEL_ERROR"`.
+    /// - `Generic`/`GenericError` both mean "unclassified error", differing only in
+    ///   whether a plain message or a boxed `source` was available when it was raised
+    ///   -- both share `"DELTA_GENERIC_ERROR"`.
+    ///
+    /// A binding that needs to tell these apart should match on the variant directly
+    /// (or a future, more granular code) rather than relying on `error_code()`.
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::KernelError(_) => "DELTA_KERNEL_ERROR",
+            Self::ObjectStore { .. } => "DELTA_OBJECT_STORE_ERROR",
+            Self::Parquet { .. } => "DELTA_PARQUET_ERROR",
+            Self::Arrow { .. } => "DELTA_ARROW_ERROR",
+            Self::InvalidJsonLog { .. } => "DELTA_INVALID_JSON_LOG",
+            Self::InvalidStatsJson { .. } => "DELTA_INVALID_STATS_JSON",
+            Self::InvalidInvariantJson { .. } => "DELTA_INVALID_INVARIANT_JSON",
+            Self::ActionParse(_) => "DELTA_ACTION_PARSE_ERROR",
+            Self::InvalidVersion(_) => "DELTA_INVALID_VERSION",
+            Self::MissingDataFile { .. } => "DELTA_MISSING_DATA_FILE",
+            Self::InvalidDateTimeString { .. } => "DELTA_INVALID_DATETIME_STRING",
+            Self::InvalidData { .. } => "DELTA_INVALID_DATA",
+            Self::NotATable(_) => "DELTA_NOT_A_TABLE",
+            Self::NoMetadata => "DELTA_NO_METADATA",
+            Self::NoSchema => "DELTA_NO_SCHEMA",
+            Self::LoadPartitions => "DELTA_NO_PARTITIONS",
+            Self::SchemaMismatch { .. } => "DELTA_SCHEMA_MISMATCH",
+            Self::PartitionError { .. } => "DELTA_PARTITION_ERROR",
+            Self::InvalidPartitionFilter { .. } => "DELTA_INVALID_PARTITION_FILTER",
+            Self::ColumnsNotPartitioned { .. } => "DELTA_COLUMNS_NOT_PARTITIONED",
+            Self::Io { .. } => "DELTA_IO_ERROR",
+            Self::CommitValidation { .. } => "DELTA_COMMIT_VALIDATION_ERROR",
+            Self::Transaction { .. } => "DELTA_TRANSACTION_ERROR",
+            Self::VersionAlreadyExists(_) => "DELTA_VERSION_EXISTS",
+            Self::Conflict { conflict, .. } => match conflict {
+                ConflictType::ConcurrentAppend => "DELTA_CONFLICT_CONCURRENT_APPEND",
+                ConflictType::ConcurrentDeleteRead => "DELTA_CONFLICT_CONCURRENT_DELETE_READ",
+                ConflictType::ConcurrentDeleteDelete => "DELTA_CONFLICT_CONCURRENT_DELETE_DELETE",
+                ConflictType::MetadataChanged => "DELTA_CONFLICT_METADATA_CHANGED",
+                ConflictType::ProtocolChanged => "DELTA_CONFLICT_PROTOCOL_CHANGED",
+            },
+            Self::VersionMismatch(_, _) => "DELTA_VERSION_MISMATCH",
+            Self::MissingFeature { .. } => "DELTA_MISSING_FEATURE",
+            Self::InvalidTableLocation(_) => "DELTA_INVALID_TABLE_LOCATION",
+            Self::SerializeLogJson { .. } => "DELTA_SERIALIZE_LOG_JSON_ERROR",
+            Self::SerializeSchemaJson { .. } => "DELTA_SERIALIZE_SCHEMA_JSON_ERROR",
+            Self::Generic(_) => "DELTA_GENERIC_ERROR",
+            Self::GenericError { .. } => "DELTA_GENERIC_ERROR",
+            Self::Kernel { .. } => "DELTA_KERNEL_ERROR",
+            Self::MetadataError(_) => "DELTA_METADATA_ERROR",
+            Self::NotInitialized => "DELTA_NOT_INITIALIZED",
+            Self::NotInitializedWithFiles(_) => "DELTA_NOT_INITIALIZED_WITH_FILES",
+            Self::ChangeDataNotRecorded { .. } => "DELTA_CHANGE_DATA_NOT_RECORDED",
+            Self::ChangeDataNotEnabled { .. } => "DELTA_CHANGE_DATA_NOT_ENABLED",
+            Self::ChangeDataInvalidVersionRange { .. } => "DELTA_CHANGE_DATA_INVALID_VERSION_RANGE",
+            Self::ChangeDataTimestampGreaterThanCommit { .. } => {
+                "DELTA_CHANGE_DATA_TIMESTAMP_GREATER_THAN_COMMIT"
+            }
+            Self::NoStartingVersionOrTimestamp => "DELTA_NO_STARTING_VERSION_OR_TIMESTAMP",
+        }
+    }
+}
+
+/// Broad category of a [`DeltaTableError`], used to drive automatic retry behavior in
+/// commit loops and object-store callers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The failure is expected to be temporary, e.g. a network blip, an object-store
+    /// throttling response, or an I/O hiccup. Retrying with backoff is usually the
+    /// right move.
+    Transient,
+    /// Another writer won a commit race. Depending on the write, the caller may be
+    /// able to reload the log and retry.
+    Conflict,
+    /// The caller supplied data, a schema, or a partition that delta-rs will never
+    /// accept. Retrying without changing the input will not help.
+    InvalidInput,
+    /// The table or log is corrupted, or the error otherwise indicates a bug or an
+    /// unrecoverable state. Retrying will not help.
+    Fatal,
+}
+
+/// Object-store backends surface rate limiting and transient server errors as a boxed
+/// source error (usually a `reqwest::Error`) inside [`ObjectStoreError::Generic`] or
+/// [`ObjectStoreError::NotSupported`], so retryable conditions are classified by
+/// variant first and, failing that, by walking the source chain for a structured HTTP
+/// status or a known-transient `reqwest` condition. We deliberately do not pattern
+/// match on the rendered `Display` text: it can embed unrelated digits (an object key
+/// like `s3://bucket/500/part.parquet`, a byte count, ...) that would false-positive.
+fn classify_object_store_error(source: &ObjectStoreError) -> ErrorKind {
+    match source {
+        ObjectStoreError::NotFound { .. } => ErrorKind::Fatal,
+        ObjectStoreError::AlreadyExists { .. } => ErrorKind::Conflict,
+        ObjectStoreError::Precondition { .. } | ObjectStoreError::NotModified { .. } => {
+            ErrorKind::Conflict
+        }
+        ObjectStoreError::PermissionDenied { .. } | ObjectStoreError::Unauthenticated { .. } => {
+            ErrorKind::Fatal
+        }
+        ObjectStoreError::NotImplemented | ObjectStoreError::UnknownConfigurationKey { .. } => {
+            ErrorKind::InvalidInput
+        }
+        ObjectStoreError::JoinError { .. } => ErrorKind::Transient,
+        ObjectStoreError::Generic { source, .. } | ObjectStoreError::NotSupported { source } => {
+            if has_retryable_status(source.as_ref()) {
+                ErrorKind::Transient
+            } else {
+                ErrorKind::Fatal
+            }
+        }
+        _ => ErrorKind::Fatal,
+    }
+}
+
+/// Walk `source`'s error chain looking for a `reqwest::Error` and inspect its
+/// structured status code / timeout flag, rather than grepping the rendered message.
+/// Returns `false` if no such error is found anywhere in the chain.
+fn has_retryable_status(source: &(dyn std::error::Error + 'static)) -> bool {
+    let mut cause = Some(source);
+    while let Some(err) = cause {
+        if let Some(reqwest_err) = err.downcast_ref::<reqwest::Error>() {
+            if let Some(status) = reqwest_err.status() {
+                return status.is_server_error()
+                    || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+            }
+            if reqwest_err.is_timeout() || reqwest_err.is_connect() {
+                return true;
+            }
+        }
+        cause = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_maps_commit_races_to_conflict() {
+        assert_eq!(
+            DeltaTableError::VersionAlreadyExists(3).classify(),
+            ErrorKind::Conflict
+        );
+        assert_eq!(
+            DeltaTableError::VersionMismatch(3, 2).classify(),
+            ErrorKind::Conflict
+        );
+    }
+
+    #[test]
+    fn classify_maps_bad_input_to_invalid_input() {
+        let err = DeltaTableError::SchemaMismatch { msg: "boom".into() };
+        assert_eq!(err.classify(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn classify_maps_change_data_errors_to_invalid_input() {
+        // These belong to the same caller-input-error family as
+        // ChangeDataInvalidVersionRange/ChangeDataTimestampGreaterThanCommit and must
+        // not silently fall through to the Fatal catch-all.
+        assert_eq!(
+            DeltaTableError::ChangeDataNotRecorded {
+                version: 1,
+                start: 0,
+                end: 2
+            }
+            .classify(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            DeltaTableError::ChangeDataNotEnabled { version: 1 }.classify(),
+            ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn classify_maps_io_to_transient() {
+        let err = DeltaTableError::Io {
+            source: std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"),
+        };
+        assert!(err.is_transient());
+    }
+
+    #[test]
+    fn classify_defaults_unknown_variants_to_fatal() {
+        assert_eq!(DeltaTableError::NoMetadata.classify(), ErrorKind::Fatal);
+    }
+
+    #[test]
+    fn has_retryable_status_ignores_unrelated_errors() {
+        // A plain io::Error (no reqwest::Error anywhere in the chain) must never be
+        // treated as retryable just because its message happens to contain digits.
+        let io_err = std::io::Error::new(std::io::ErrorKind::Other, "wrote 503 bytes to disk");
+        assert!(!has_retryable_status(&io_err));
+    }
+
+    #[test]
+    fn error_code_is_stable_per_variant() {
+        assert_eq!(
+            DeltaTableError::VersionAlreadyExists(1).error_code(),
+            "DELTA_VERSION_EXISTS"
+        );
+        assert_eq!(
+            DeltaTableError::SchemaMismatch { msg: "x".into() }.error_code(),
+            "DELTA_SCHEMA_MISMATCH"
+        );
+        assert_eq!(
+            DeltaTableError::NotATable("x".into()).error_code(),
+            "DELTA_NOT_A_TABLE"
+        );
+    }
 }
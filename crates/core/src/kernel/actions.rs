@@ -0,0 +1,194 @@
+//! Parsing of Delta log actions (`add`, `remove`, `metaData`, `protocol`, ...) out of a
+//! single line of a commit or checkpoint JSON log.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::errors::{ActionParseError, DeltaResult, DeltaTableError};
+
+/// A single parsed entry from a Delta transaction log line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// A data file was added to the table.
+    Add(Add),
+    /// A data file was removed from the table.
+    Remove(Remove),
+    /// The table's schema, partitioning, or configuration changed.
+    MetaData(MetaData),
+    /// The table's supported reader/writer protocol changed.
+    Protocol(Protocol),
+}
+
+/// The `add` log action: a data file was added to the table.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Add {
+    /// Relative path of the data file, from the table root.
+    pub path: String,
+    /// Size of the data file in bytes.
+    pub size: i64,
+    /// Partition values of the data file, keyed by partition column name.
+    #[serde(rename = "partitionValues")]
+    pub partition_values: HashMap<String, Option<String>>,
+    /// Last modification time of the data file, in milliseconds since the Unix epoch.
+    #[serde(rename = "modificationTime")]
+    pub modification_time: i64,
+    /// Per-column statistics for the data file, as an opaque JSON string.
+    pub stats: Option<String>,
+}
+
+/// The `remove` log action: a data file was removed from the table.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Remove {
+    /// Relative path of the removed data file, from the table root.
+    pub path: String,
+    /// Time the file was removed, in milliseconds since the Unix epoch.
+    #[serde(rename = "deletionTimestamp")]
+    pub deletion_timestamp: Option<i64>,
+    /// Partition values of the removed data file, keyed by partition column name.
+    #[serde(rename = "partitionValues")]
+    pub partition_values: Option<HashMap<String, Option<String>>>,
+}
+
+/// The `metaData` log action: the table's schema, partitioning, or configuration
+/// changed.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetaData {
+    /// Unique identifier for this table metadata.
+    pub id: String,
+    /// The table schema, serialized as a JSON string.
+    #[serde(rename = "schemaString")]
+    pub schema_string: String,
+    /// Column names the table is partitioned by, in order.
+    #[serde(rename = "partitionColumns")]
+    pub partition_columns: Vec<String>,
+    /// Table configuration properties.
+    pub configuration: HashMap<String, Option<String>>,
+}
+
+/// The `protocol` log action: the table's supported reader/writer versions changed.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Protocol {
+    /// Minimum reader protocol version required to read the table.
+    #[serde(rename = "minReaderVersion")]
+    pub min_reader_version: i32,
+    /// Minimum writer protocol version required to write to the table.
+    #[serde(rename = "minWriterVersion")]
+    pub min_writer_version: i32,
+}
+
+/// Parse one line of a Delta log -- a single JSON object naming exactly one action --
+/// into an [`Action`].
+///
+/// Any field that fails to parse is attributed to the specific action type and dotted
+/// field path via [`DeltaTableError::ActionParse`], e.g. `add.stats` in version `42`,
+/// rather than the opaque `DeltaTableError::InvalidJsonLog` previously produced for
+/// every malformed line. A line that is not valid JSON at all is still reported as
+/// `DeltaTableError::InvalidJsonLog`.
+pub fn parse_action_line(line: &str, version: i64, offset: usize) -> DeltaResult<Action> {
+    let value: serde_json::Value =
+        serde_json::from_str(line).map_err(|json_err| DeltaTableError::InvalidJsonLog {
+            json_err,
+            line: line.to_string(),
+            version,
+        })?;
+
+    if let Some(raw) = value.get("add") {
+        return Ok(Action::Add(parse_action_field(
+            "add", raw, version, offset,
+        )?));
+    }
+    if let Some(raw) = value.get("remove") {
+        return Ok(Action::Remove(parse_action_field(
+            "remove", raw, version, offset,
+        )?));
+    }
+    if let Some(raw) = value.get("metaData") {
+        return Ok(Action::MetaData(parse_action_field(
+            "metaData", raw, version, offset,
+        )?));
+    }
+    if let Some(raw) = value.get("protocol") {
+        return Ok(Action::Protocol(parse_action_field(
+            "protocol", raw, version, offset,
+        )?));
+    }
+
+    Err(DeltaTableError::generic(format!(
+        "log line at version {version} does not contain a recognized action \
+         (add/remove/metaData/protocol)"
+    )))
+}
+
+/// Deserialize `raw` as `T`, and on failure report exactly which field of `action`
+/// didn't parse (e.g. `stats`) rather than just the byte offset of the whole line.
+fn parse_action_field<T: serde::de::DeserializeOwned>(
+    action: &'static str,
+    raw: &serde_json::Value,
+    version: i64,
+    offset: usize,
+) -> DeltaResult<T> {
+    serde_path_to_error::deserialize(raw.clone()).map_err(|err| {
+        let field = err.path().to_string();
+        DeltaTableError::ActionParse(ActionParseError::new(
+            action,
+            field,
+            version,
+            offset,
+            err.into_inner(),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_add_action() {
+        let line = r#"{"add":{"path":"f.parquet","size":10,"partitionValues":{},"modificationTime":1,"stats":null}}"#;
+        let action = parse_action_line(line, 1, 0).unwrap();
+        assert!(matches!(action, Action::Add(add) if add.path == "f.parquet"));
+    }
+
+    #[test]
+    fn reports_action_and_field_on_type_mismatch() {
+        let line = r#"{"add":{"path":"f.parquet","size":"not-a-number","partitionValues":{},"modificationTime":1,"stats":null}}"#;
+        let err = parse_action_line(line, 7, 42).unwrap_err();
+        match err {
+            DeltaTableError::ActionParse(e) => {
+                assert_eq!(e.action, "add");
+                assert_eq!(e.field, "size");
+                assert_eq!(e.version, 7);
+                assert_eq!(e.offset, 42);
+            }
+            other => panic!("expected ActionParse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_remove_action_field_path() {
+        let line = r#"{"remove":{"path":"f.parquet","deletionTimestamp":"oops"}}"#;
+        let err = parse_action_line(line, 3, 0).unwrap_err();
+        match err {
+            DeltaTableError::ActionParse(e) => {
+                assert_eq!(e.action, "remove");
+                assert_eq!(e.field, "deletionTimestamp");
+            }
+            other => panic!("expected ActionParse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unrecognized_action_key_is_rejected() {
+        let line = r#"{"commitInfo":{}}"#;
+        assert!(parse_action_line(line, 1, 0).is_err());
+    }
+
+    #[test]
+    fn malformed_json_still_reports_invalid_json_log() {
+        let line = "{not json}";
+        let err = parse_action_line(line, 1, 0).unwrap_err();
+        assert!(matches!(err, DeltaTableError::InvalidJsonLog { .. }));
+    }
+}
@@ -0,0 +1,14 @@
+//! Delta kernel: log actions, snapshot state, and transaction/commit handling.
+
+pub mod actions;
+pub mod transaction;
+
+/// Error raised while interpreting the Delta log at the kernel level, e.g. an
+/// unsupported protocol feature or a malformed checkpoint.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The table requires a reader/writer feature this version of delta-rs does not
+    /// implement.
+    #[error("Unsupported reader/writer feature: {0}")]
+    UnsupportedFeature(String),
+}
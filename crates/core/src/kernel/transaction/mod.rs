@@ -0,0 +1,236 @@
+//! Transaction/commit handling: building, validating, and committing new log versions.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::errors::{
+    check_commit_conflict, CommitFileSummary, ConflictType, DeltaResult, DeltaTableError, ErrorKind,
+};
+
+/// Error raised while validating the actions that make up a commit before it is
+/// written to the log.
+#[derive(Debug, thiserror::Error)]
+pub enum CommitBuilderError {
+    /// The commit would write files whose partition values don't match the
+    /// transaction's declared partitioning.
+    #[error("Commit contains files with unexpected partition values")]
+    PartitionMismatch,
+}
+
+/// Error raised while attempting to commit a transaction to the log.
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionError {
+    /// The version this transaction is trying to commit is already taken.
+    #[error("version {0} already exists")]
+    VersionExists(i64),
+}
+
+/// Per-transaction context needed to classify an optimistic-concurrency conflict
+/// against a winning concurrent commit: the files this transaction itself removes, and
+/// the files/partitions its read predicate touched. See [`check_commit_conflict`].
+#[derive(Debug, Default, Clone)]
+pub struct TransactionState {
+    /// Paths of files this transaction removes.
+    pub removed_files: HashSet<String>,
+    /// Exact paths of files this transaction's read predicate read, when known.
+    pub read_files: HashSet<String>,
+    /// Partition values touched by the transaction's read predicate, used by the
+    /// delete-read check as a fallback when `read_files` isn't known, and by the
+    /// concurrent-append check whenever non-empty.
+    pub read_partitions: HashSet<String>,
+}
+
+/// The outcome of a single failed commit attempt passed to [`commit_with_retry`].
+pub enum CommitAttemptFailure {
+    /// The attempt failed with an ordinary error (network, serialization, ...); it is
+    /// handled via [`DeltaTableError::classify`].
+    Error(DeltaTableError),
+    /// Another writer won the race and committed `version` first; `winner` summarizes
+    /// its actions so the conflict can be classified via [`check_commit_conflict`].
+    WonBy {
+        /// The version the other writer committed.
+        version: i64,
+        /// Summary of the winning commit's actions.
+        winner: CommitFileSummary,
+    },
+}
+
+/// Maximum number of attempts [`commit_with_retry`] will make before giving up. If the
+/// last attempt failed transiently, that error is surfaced directly; otherwise (the
+/// exhaustion happened while repeatedly losing the commit race) a
+/// [`DeltaTableError::VersionAlreadyExists`] is synthesized, since that's what actually
+/// happened.
+const MAX_COMMIT_ATTEMPTS: u32 = 10;
+
+/// Drive a commit loop for `txn` that reacts to a failed attempt as follows:
+/// - [`CommitAttemptFailure::Error`] classified as [`ErrorKind::Transient`]: back off
+///   and retry at the same version.
+/// - [`CommitAttemptFailure::Error`] classified as anything else: give up immediately.
+/// - [`CommitAttemptFailure::WonBy`]: run [`check_commit_conflict`]. A `None` result or
+///   [`ConflictType::ConcurrentAppend`] is safe to rebase onto, so reload the log and
+///   retry at `version + 1`; any other [`ConflictType`] is returned as a typed
+///   [`DeltaTableError::Conflict`], since retrying would not help.
+///
+/// If [`MAX_COMMIT_ATTEMPTS`] is exhausted, the error returned reflects why: a run of
+/// transient failures (e.g. a persistently timing-out object store) returns the last
+/// such error rather than a fabricated [`DeltaTableError::VersionAlreadyExists`], so
+/// callers (and the Python binding's [`DeltaTableError::error_code`]-based exception
+/// mapping) aren't misled into treating a network failure as a commit conflict.
+///
+/// `attempt` performs a single attempt to write `version` to the log (e.g. a PUT with
+/// an object-store precondition).
+pub async fn commit_with_retry<F, Fut>(
+    txn: &TransactionState,
+    mut version: i64,
+    mut attempt: F,
+) -> DeltaResult<i64>
+where
+    F: FnMut(i64) -> Fut,
+    Fut: std::future::Future<Output = Result<(), CommitAttemptFailure>>,
+{
+    let mut last_transient_err: Option<DeltaTableError> = None;
+    for round in 0..MAX_COMMIT_ATTEMPTS {
+        match attempt(version).await {
+            Ok(()) => return Ok(version),
+            Err(CommitAttemptFailure::Error(err)) => match err.classify() {
+                ErrorKind::Transient => {
+                    tokio::time::sleep(backoff(round)).await;
+                    last_transient_err = Some(err);
+                }
+                ErrorKind::Conflict | ErrorKind::InvalidInput | ErrorKind::Fatal => {
+                    return Err(err)
+                }
+            },
+            Err(CommitAttemptFailure::WonBy {
+                version: won_version,
+                winner,
+            }) => {
+                last_transient_err = None;
+                match check_commit_conflict(
+                    &winner,
+                    &txn.read_partitions,
+                    &txn.read_files,
+                    &txn.removed_files,
+                ) {
+                    None | Some(ConflictType::ConcurrentAppend) => {
+                        version = won_version + 1;
+                    }
+                    Some(conflict) => {
+                        return Err(DeltaTableError::Conflict {
+                            conflict,
+                            version: won_version,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    // `version` has already been advanced past the last winning commit, so the version
+    // that actually exists is the one before it.
+    Err(last_transient_err.unwrap_or(DeltaTableError::VersionAlreadyExists(version - 1)))
+}
+
+/// Exponential backoff for retry attempt number `round`: 100ms, doubling each round,
+/// up to 1.6s from round 4 on (and never above 2s).
+fn backoff(round: u32) -> Duration {
+    Duration::from_millis(100 * 2u64.pow(round.min(4))).min(Duration::from_secs(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn retries_transient_errors_until_success() {
+        let txn = TransactionState::default();
+        let calls = AtomicU32::new(0);
+        let result = commit_with_retry(&txn, 1, |_version| {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if n < 2 {
+                    Err(CommitAttemptFailure::Error(DeltaTableError::Io {
+                        source: std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"),
+                    }))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn rebases_and_retries_on_disjoint_concurrent_append() {
+        let mut txn = TransactionState::default();
+        txn.read_partitions.insert("date=2024-01-01".to_string());
+
+        let result = commit_with_retry(&txn, 5, |version| async move {
+            if version == 5 {
+                let mut winner = CommitFileSummary::default();
+                winner
+                    .added_files
+                    .insert("date=2024-01-02/a.parquet".into());
+                winner.touched_partitions.insert("date=2024-01-02".into());
+                Err(CommitAttemptFailure::WonBy { version: 5, winner })
+            } else {
+                Ok(())
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 6);
+    }
+
+    #[tokio::test]
+    async fn aborts_with_typed_conflict_on_concurrent_delete_read() {
+        let mut txn = TransactionState::default();
+        txn.read_files.insert("a.parquet".to_string());
+
+        let result = commit_with_retry(&txn, 5, |_version| async {
+            let mut winner = CommitFileSummary::default();
+            winner.removed_files.insert("a.parquet".into());
+            Err(CommitAttemptFailure::WonBy { version: 5, winner })
+        })
+        .await;
+
+        match result {
+            Err(DeltaTableError::Conflict { conflict, version }) => {
+                assert_eq!(conflict, ConflictType::ConcurrentDeleteRead);
+                assert_eq!(version, 5);
+            }
+            other => panic!("expected Conflict error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn surfaces_last_transient_error_when_attempts_are_exhausted() {
+        // Every attempt times out -- retries are exhausted without ever actually
+        // losing a commit race, so the real I/O error must come back, not a fabricated
+        // VersionAlreadyExists.
+        let txn = TransactionState::default();
+        let result = commit_with_retry(&txn, 1, |_version| async {
+            Err(CommitAttemptFailure::Error(DeltaTableError::Io {
+                source: std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"),
+            }))
+        })
+        .await;
+        assert!(matches!(result, Err(DeltaTableError::Io { .. })));
+    }
+
+    #[tokio::test]
+    async fn gives_up_immediately_on_invalid_input() {
+        let txn = TransactionState::default();
+        let result = commit_with_retry(&txn, 1, |_version| async {
+            Err(CommitAttemptFailure::Error(
+                DeltaTableError::SchemaMismatch { msg: "boom".into() },
+            ))
+        })
+        .await;
+        assert!(matches!(
+            result,
+            Err(DeltaTableError::SchemaMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+//! Core crate for the delta-rs project
+
+pub mod errors;
+pub mod kernel;
+pub mod storage;
+
+pub use errors::{DeltaResult, DeltaTableError};
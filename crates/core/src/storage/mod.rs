@@ -0,0 +1,3 @@
+//! Object-store access helpers.
+
+pub mod retry;
@@ -0,0 +1,70 @@
+//! Retry wrapper for object-store operations.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::errors::DeltaResult;
+
+/// Maximum number of attempts [`with_retry`] will make before giving up and returning
+/// the last error.
+const MAX_ATTEMPTS: u32 = 5;
+
+/// Run `op`, retrying with exponential backoff while the returned error is classified
+/// as [`ErrorKind::Transient`](crate::errors::ErrorKind::Transient) (e.g. object-store
+/// throttling or a timed-out connection), and returning immediately on any other error.
+pub async fn with_retry<T, F, Fut>(mut op: F) -> DeltaResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = DeltaResult<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if err.is_transient() && attempt + 1 < MAX_ATTEMPTS => {
+                tokio::time::sleep(Duration::from_millis(100 * 2u64.pow(attempt))).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::DeltaTableError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn retries_transient_errors_then_succeeds() {
+        let attempts = AtomicU32::new(0);
+        let result: DeltaResult<i32> = with_retry(|| {
+            let n = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if n < 2 {
+                    Err(DeltaTableError::Io {
+                        source: std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"),
+                    })
+                } else {
+                    Ok(42)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_fatal_errors() {
+        let attempts = AtomicU32::new(0);
+        let result: DeltaResult<()> = with_retry(|| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(DeltaTableError::NoMetadata) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
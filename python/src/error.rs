@@ -0,0 +1,87 @@
+//! Mapping from [`DeltaTableError`] to Python exceptions.
+//!
+//! We branch on [`DeltaTableError::error_code`] rather than string-matching
+//! `Display` output, so this mapping keeps working across delta-rs releases even as
+//! error messages are reworded.
+
+use deltalake_core::errors::DeltaTableError;
+use pyo3::exceptions::{PyIOError, PyOSError, PyValueError};
+use pyo3::{create_exception, PyErr};
+
+create_exception!(_internal, TableNotFoundError, PyValueError);
+create_exception!(_internal, CommitFailedError, PyOSError);
+create_exception!(_internal, DeltaProtocolError, PyValueError);
+create_exception!(_internal, SchemaMismatchError, PyValueError);
+
+/// Convert a [`DeltaTableError`] into the Python exception type its `error_code()`
+/// maps to, so callers can `except TableNotFoundError` instead of string-matching the
+/// message.
+pub fn inner_to_py_err(err: DeltaTableError) -> PyErr {
+    match err.error_code() {
+        "DELTA_NOT_A_TABLE" => TableNotFoundError::new_err(err.to_string()),
+        "DELTA_VERSION_EXISTS" | "DELTA_VERSION_MISMATCH" => {
+            CommitFailedError::new_err(err.to_string())
+        }
+        code if code.starts_with("DELTA_CONFLICT_") => CommitFailedError::new_err(err.to_string()),
+        "DELTA_SCHEMA_MISMATCH" => SchemaMismatchError::new_err(err.to_string()),
+        "DELTA_CHANGE_DATA_NOT_ENABLED" | "DELTA_CHANGE_DATA_NOT_RECORDED" => {
+            DeltaProtocolError::new_err(err.to_string())
+        }
+        "DELTA_IO_ERROR" => PyIOError::new_err(err.to_string()),
+        _ => PyValueError::new_err(err.to_string()),
+    }
+}
+
+impl From<DeltaTableError> for PyErr {
+    fn from(err: DeltaTableError) -> Self {
+        inner_to_py_err(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicts_map_to_commit_failed_regardless_of_conflict_type() {
+        use deltalake_core::errors::ConflictType;
+
+        let err = DeltaTableError::Conflict {
+            conflict: ConflictType::ConcurrentDeleteRead,
+            version: 7,
+        };
+        assert_eq!(err.error_code(), "DELTA_CONFLICT_CONCURRENT_DELETE_READ");
+
+        pyo3::prepare_freethreaded_python();
+        pyo3::Python::with_gil(|py| {
+            let py_err: PyErr = inner_to_py_err(err);
+            assert!(py_err.is_instance_of::<CommitFailedError>(py));
+        });
+
+        for conflict in [
+            ConflictType::ConcurrentAppend,
+            ConflictType::ConcurrentDeleteDelete,
+            ConflictType::MetadataChanged,
+            ConflictType::ProtocolChanged,
+        ] {
+            let err = DeltaTableError::Conflict {
+                conflict,
+                version: 7,
+            };
+            pyo3::Python::with_gil(|py| {
+                let py_err: PyErr = inner_to_py_err(err);
+                assert!(py_err.is_instance_of::<CommitFailedError>(py));
+            });
+        }
+    }
+
+    #[test]
+    fn not_a_table_maps_to_table_not_found() {
+        let err = DeltaTableError::not_a_table("s3://bucket/table");
+        pyo3::prepare_freethreaded_python();
+        pyo3::Python::with_gil(|py| {
+            let py_err: PyErr = inner_to_py_err(err);
+            assert!(py_err.is_instance_of::<TableNotFoundError>(py));
+        });
+    }
+}